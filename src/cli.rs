@@ -0,0 +1,339 @@
+//! Command-line surface over the analyzer: subcommands read documents from
+//! file paths (one per `--input`) or stdin (one document per line) and
+//! render `TextStats`/`InvertedIndex` as CSV (word-frequency subcommands)
+//! or as tab-separated lines (`search`, `query`, `suggest`).
+
+use std::fs;
+use std::io::{self, Read};
+
+use crate::index::InvertedIndex;
+use crate::tokenizer::Tokenizer;
+use crate::{analyze_text_fast, analyze_text_slow, generate_test_text};
+
+#[derive(Debug)]
+enum Command {
+    MostCommonWords { limit: usize, inputs: Vec<String> },
+    WordsFrequencies { words: Vec<String>, inputs: Vec<String> },
+    LongestWords { limit: usize, inputs: Vec<String> },
+    Search { query: String, limit: usize, inputs: Vec<String> },
+    Query { query: String, limit: usize, inputs: Vec<String> },
+    Suggest { word: String, distance: usize, inputs: Vec<String> },
+    Benchmark,
+}
+
+const USAGE: &str = "usage: analyzer <most-common-words|words-frequencies|longest-words|search|query|suggest|benchmark> \
+    [--limit N] [--distance D] [--input PATH]... [WORDS...]";
+
+fn parse_args(args: Vec<String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let subcommand = args.next().ok_or_else(|| USAGE.to_string())?;
+
+    let mut limit = 10usize;
+    let mut distance = 2usize;
+    let mut inputs = Vec::new();
+    let mut words = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--limit" => {
+                let value = args.next().ok_or("--limit requires a value")?;
+                limit = value.parse().map_err(|_| format!("invalid --limit value: {value}"))?;
+            }
+            "--distance" => {
+                let value = args.next().ok_or("--distance requires a value")?;
+                distance = value.parse().map_err(|_| format!("invalid --distance value: {value}"))?;
+            }
+            "--input" => {
+                let value = args.next().ok_or("--input requires a value")?;
+                inputs.push(value);
+            }
+            word => words.push(word.to_string()),
+        }
+    }
+
+    match subcommand.as_str() {
+        "most-common-words" => Ok(Command::MostCommonWords { limit, inputs }),
+        "longest-words" => Ok(Command::LongestWords { limit, inputs }),
+        "words-frequencies" => {
+            if words.is_empty() {
+                return Err("words-frequencies requires at least one word".to_string());
+            }
+            Ok(Command::WordsFrequencies { words, inputs })
+        }
+        "search" => {
+            if words.is_empty() {
+                return Err("search requires a query".to_string());
+            }
+            Ok(Command::Search { query: words.join(" "), limit, inputs })
+        }
+        "query" => {
+            if words.is_empty() {
+                return Err("query requires a boolean query string, e.g. 'rust AND (speed OR optim*)'".to_string());
+            }
+            Ok(Command::Query { query: words.join(" "), limit, inputs })
+        }
+        "suggest" => {
+            let word = words.into_iter().next().ok_or("suggest requires a word")?;
+            Ok(Command::Suggest { word, distance, inputs })
+        }
+        "benchmark" => Ok(Command::Benchmark),
+        other => Err(format!("unknown subcommand '{other}'\n{USAGE}")),
+    }
+}
+
+/// Loads one document per `--input` path, or one document per stdin line
+/// if no `--input` was given.
+fn load_documents(inputs: &[String]) -> io::Result<Vec<String>> {
+    if inputs.is_empty() {
+        let mut buf = String::new();
+        io::stdin().read_to_string(&mut buf)?;
+        Ok(buf.lines().map(|line| line.to_string()).filter(|line| !line.trim().is_empty()).collect())
+    } else {
+        inputs.iter().map(fs::read_to_string).collect()
+    }
+}
+
+fn print_csv(rows: &[(String, usize, usize)]) {
+    let stdout = io::stdout();
+    write_csv(&mut stdout.lock(), rows).expect("failed to write CSV to stdout");
+}
+
+/// Writes the `word,document_frequency,frequency` CSV `print_csv` prints,
+/// factored out so it can be exercised against an in-memory buffer in tests.
+fn write_csv<W: io::Write>(out: &mut W, rows: &[(String, usize, usize)]) -> io::Result<()> {
+    writeln!(out, "word,document_frequency,frequency")?;
+    for (word, document_frequency, frequency) in rows {
+        writeln!(out, "{word},{document_frequency},{frequency}")?;
+    }
+    Ok(())
+}
+
+pub fn run(args: Vec<String>) -> Result<(), String> {
+    let command = parse_args(args)?;
+
+    match command {
+        Command::Benchmark => {
+            run_benchmark();
+            Ok(())
+        }
+        Command::MostCommonWords { limit, inputs } => {
+            let docs = load_documents(&inputs).map_err(|e| e.to_string())?;
+            let index = InvertedIndex::build(&docs);
+            let combined = docs.join(" ");
+            let tokenizer = Tokenizer::builder().build();
+            let stats = analyze_text_fast(&combined, &tokenizer, limit, 0);
+
+            let rows: Vec<(String, usize, usize)> = stats
+                .top_words
+                .into_iter()
+                .map(|(word, frequency)| {
+                    let (document_frequency, _) = index.term_stats(&word);
+                    (word, document_frequency, frequency)
+                })
+                .collect();
+            print_csv(&rows);
+            Ok(())
+        }
+        Command::LongestWords { limit, inputs } => {
+            let docs = load_documents(&inputs).map_err(|e| e.to_string())?;
+            let index = InvertedIndex::build(&docs);
+            let combined = docs.join(" ");
+            let tokenizer = Tokenizer::builder().build();
+            let stats = analyze_text_fast(&combined, &tokenizer, 0, limit);
+
+            let rows: Vec<(String, usize, usize)> = stats
+                .longest_words
+                .into_iter()
+                .map(|word| {
+                    let (document_frequency, frequency) = index.term_stats(&word);
+                    (word, document_frequency, frequency)
+                })
+                .collect();
+            print_csv(&rows);
+            Ok(())
+        }
+        Command::WordsFrequencies { words, inputs } => {
+            let docs = load_documents(&inputs).map_err(|e| e.to_string())?;
+            let index = InvertedIndex::build(&docs);
+
+            let rows: Vec<(String, usize, usize)> = words
+                .into_iter()
+                .map(|word| {
+                    let clean = crate::tokenize::clean_word(&word);
+                    let (document_frequency, frequency) = index.term_stats(&clean);
+                    (clean, document_frequency, frequency)
+                })
+                .collect();
+            print_csv(&rows);
+            Ok(())
+        }
+        Command::Search { query, limit, inputs } => {
+            let docs = load_documents(&inputs).map_err(|e| e.to_string())?;
+            let index = InvertedIndex::build(&docs);
+            for (doc_id, score) in index.search(&query, limit) {
+                println!("{doc_id}\t{score:.4}");
+            }
+            Ok(())
+        }
+        Command::Query { query, limit, inputs } => {
+            let docs = load_documents(&inputs).map_err(|e| e.to_string())?;
+            let index = InvertedIndex::build(&docs);
+            let operation = crate::query::parse(&query)?;
+            println!("{operation:?}");
+            for (doc_id, score) in operation.search(&index, limit) {
+                println!("{doc_id}\t{score:.4}");
+            }
+            Ok(())
+        }
+        Command::Suggest { word, distance, inputs } => {
+            let docs = load_documents(&inputs).map_err(|e| e.to_string())?;
+            let combined = docs.join(" ");
+            let tokenizer = Tokenizer::builder().build();
+            let stats = analyze_text_fast(&combined, &tokenizer, 0, 0);
+            for (suggestion, freq) in stats.suggest(&word, distance) {
+                println!("{suggestion}\t{freq}");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// The original slow-vs-fast word-count demo, kept behind its own
+/// subcommand now that `main` dispatches to real CLI tooling.
+fn run_benchmark() {
+    let text = generate_test_text(50_000);
+    println!("Analyzing {} bytes of text...\n", text.len());
+
+    let stats_slow = analyze_text_slow(&text);
+    println!("SLOW VERSION:");
+    println!("  Unique words: {}", stats_slow.word_count);
+    println!("  Total chars: {}", stats_slow.char_count);
+    println!("  Top 10 words: {:?}", stats_slow.top_words);
+    println!("  Longest words: {:?}", stats_slow.longest_words);
+    println!("  Time: {} ms\n", stats_slow.time_ms);
+
+    let raw_tokenizer = Tokenizer::builder().build();
+    let stats_fast = analyze_text_fast(&text, &raw_tokenizer, 10, 5);
+    println!("FAST VERSION:");
+    println!("  Unique words: {}", stats_fast.word_count);
+    println!("  Total chars: {}", stats_fast.char_count);
+    println!("  Top 10 words: {:?}", stats_fast.top_words);
+    println!("  Longest words: {:?}", stats_fast.longest_words);
+    println!("  Time: {} ms", stats_fast.time_ms);
+
+    let speedup = stats_slow.time_ms as f64 / stats_fast.time_ms.max(1) as f64;
+    println!("\nSpeedup: {:.1}x faster!", speedup);
+
+    let stemmed_tokenizer = Tokenizer::builder()
+        .stem(true)
+        .stopwords(crate::tokenizer::default_stopwords())
+        .build();
+    let stats_stemmed = analyze_text_fast(&text, &stemmed_tokenizer, 10, 5);
+    println!("\nSTEMMED VERSION (stemming + stopwords):");
+    println!("  Unique words: {}", stats_stemmed.word_count);
+    println!("  Top 10 words: {:?}", stats_stemmed.top_words);
+    println!("  Time: {} ms", stats_stemmed.time_ms);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(words: &[&str]) -> Vec<String> {
+        words.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn missing_subcommand_is_an_error() {
+        assert!(parse_args(args(&[])).is_err());
+    }
+
+    #[test]
+    fn unknown_subcommand_is_an_error() {
+        assert!(parse_args(args(&["not-a-command"])).is_err());
+    }
+
+    #[test]
+    fn words_frequencies_requires_at_least_one_word() {
+        assert!(parse_args(args(&["words-frequencies"])).is_err());
+        match parse_args(args(&["words-frequencies", "rust"])) {
+            Ok(Command::WordsFrequencies { words, .. }) => assert_eq!(words, vec!["rust".to_string()]),
+            other => panic!("expected WordsFrequencies, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn search_and_query_require_a_query_string() {
+        assert!(parse_args(args(&["search"])).is_err());
+        assert!(parse_args(args(&["query"])).is_err());
+    }
+
+    #[test]
+    fn suggest_requires_a_word() {
+        assert!(parse_args(args(&["suggest"])).is_err());
+        match parse_args(args(&["suggest", "wrod"])) {
+            Ok(Command::Suggest { word, .. }) => assert_eq!(word, "wrod"),
+            other => panic!("expected Suggest, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn limit_distance_and_input_flags_are_parsed() {
+        match parse_args(args(&[
+            "most-common-words",
+            "--limit",
+            "5",
+            "--input",
+            "a.txt",
+            "--input",
+            "b.txt",
+        ])) {
+            Ok(Command::MostCommonWords { limit, inputs }) => {
+                assert_eq!(limit, 5);
+                assert_eq!(inputs, vec!["a.txt".to_string(), "b.txt".to_string()]);
+            }
+            other => panic!("expected MostCommonWords, got {other:?}"),
+        }
+
+        match parse_args(args(&["suggest", "rust", "--distance", "3"])) {
+            Ok(Command::Suggest { distance, .. }) => assert_eq!(distance, 3),
+            other => panic!("expected Suggest, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn limit_and_distance_reject_non_numeric_values() {
+        assert!(parse_args(args(&["most-common-words", "--limit", "nope"])).is_err());
+        assert!(parse_args(args(&["suggest", "rust", "--distance", "nope"])).is_err());
+    }
+
+    #[test]
+    fn flags_missing_their_value_are_an_error() {
+        assert!(parse_args(args(&["most-common-words", "--limit"])).is_err());
+        assert!(parse_args(args(&["most-common-words", "--input"])).is_err());
+    }
+
+    #[test]
+    fn and_or_keywords_pass_through_into_the_query_string() {
+        match parse_args(args(&["query", "rust", "AND", "(speed", "OR", "optim*)"])) {
+            Ok(Command::Query { query, .. }) => assert_eq!(query, "rust AND (speed OR optim*)"),
+            other => panic!("expected Query, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn write_csv_renders_header_and_rows() {
+        let rows = vec![("rust".to_string(), 2, 5), ("cargo".to_string(), 1, 1)];
+        let mut buf = Vec::new();
+        write_csv(&mut buf, &rows).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert_eq!(out, "word,document_frequency,frequency\nrust,2,5\ncargo,1,1\n");
+    }
+
+    #[test]
+    fn write_csv_with_no_rows_is_just_the_header() {
+        let mut buf = Vec::new();
+        write_csv(&mut buf, &[]).unwrap();
+        assert_eq!(String::from_utf8(buf).unwrap(), "word,document_frequency,frequency\n");
+    }
+}
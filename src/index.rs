@@ -0,0 +1,232 @@
+//! In-memory inverted index with BM25 ranked search over many documents.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::tokenize::tokenize;
+
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+
+/// Postings-list based index: per term, which documents contain it and how
+/// often, plus the bits of corpus statistics BM25 needs (`avgdl`, `N`).
+#[derive(Debug, Default)]
+pub struct InvertedIndex {
+    postings: HashMap<String, Vec<(usize, usize)>>, // term -> [(doc_id, tf)]
+    doc_lengths: Vec<usize>,
+    avgdl: f64,
+    n_docs: usize,
+}
+
+impl InvertedIndex {
+    /// Builds an index over `docs`, tokenizing each with the same
+    /// ASCII lowercase/alphabetic cleaning `analyze_text_fast` uses, so
+    /// indexing and querying always normalize the same way.
+    pub fn build(docs: &[String]) -> Self {
+        let mut postings: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+        let mut doc_lengths = Vec::with_capacity(docs.len());
+
+        for (doc_id, doc) in docs.iter().enumerate() {
+            let mut term_freq: HashMap<String, usize> = HashMap::new();
+            let mut len = 0;
+            for word in tokenize(doc) {
+                *term_freq.entry(word).or_insert(0) += 1;
+                len += 1;
+            }
+            doc_lengths.push(len);
+            for (term, tf) in term_freq {
+                postings.entry(term).or_default().push((doc_id, tf));
+            }
+        }
+
+        let n_docs = docs.len();
+        let avgdl = if n_docs == 0 {
+            0.0
+        } else {
+            doc_lengths.iter().sum::<usize>() as f64 / n_docs as f64
+        };
+
+        InvertedIndex { postings, doc_lengths, avgdl, n_docs }
+    }
+
+    fn idf(&self, df: usize) -> f64 {
+        ((self.n_docs as f64 - df as f64 + 0.5) / (df as f64 + 0.5) + 1.0).ln()
+    }
+
+    /// BM25 score of every document containing at least one of `terms`,
+    /// optionally restricted to `candidates` (e.g. a boolean query's
+    /// matching doc-id set).
+    fn score_terms(&self, terms: &[String], candidates: Option<&HashSet<usize>>) -> HashMap<usize, f64> {
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+
+        for term in terms {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+            let idf = self.idf(postings.len());
+
+            for &(doc_id, tf) in postings {
+                if candidates.is_some_and(|c| !c.contains(&doc_id)) {
+                    continue;
+                }
+                let dl = self.doc_lengths[doc_id] as f64;
+                let tf = tf as f64;
+                let denom = tf + K1 * (1.0 - B + B * dl / self.avgdl);
+                *scores.entry(doc_id).or_insert(0.0) += idf * (tf * (K1 + 1.0)) / denom;
+            }
+        }
+
+        scores
+    }
+
+    /// Ranks `scores` and keeps the `top_k`, using the same BinaryHeap
+    /// top-k pattern `top_words` uses.
+    fn top_k(scores: HashMap<usize, f64>, top_k: usize) -> Vec<(usize, f64)> {
+        let mut heap: BinaryHeap<ScoredDoc> = scores
+            .into_iter()
+            .map(|(doc_id, score)| ScoredDoc { score, doc_id })
+            .collect();
+
+        let mut results = Vec::with_capacity(top_k);
+        for _ in 0..top_k {
+            match heap.pop() {
+                Some(ScoredDoc { score, doc_id }) => results.push((doc_id, score)),
+                None => break,
+            }
+        }
+        results
+    }
+
+    /// Returns the `top_k` documents for `query`, ranked by BM25 score.
+    pub fn search(&self, query: &str, top_k: usize) -> Vec<(usize, f64)> {
+        let terms = tokenize(query);
+        Self::top_k(self.score_terms(&terms, None), top_k)
+    }
+
+    /// Returns the `top_k` documents among `candidates`, ranked by the
+    /// combined BM25 score of `terms` — used to order boolean query
+    /// results (see [`crate::query::Operation`]).
+    pub fn search_subset(&self, terms: &[String], candidates: &HashSet<usize>, top_k: usize) -> Vec<(usize, f64)> {
+        Self::top_k(self.score_terms(terms, Some(candidates)), top_k)
+    }
+
+    /// Doc-ids containing `term` exactly (no stemming/fuzzing).
+    pub fn doc_ids(&self, term: &str) -> HashSet<usize> {
+        self.postings
+            .get(term)
+            .map(|postings| postings.iter().map(|&(doc_id, _)| doc_id).collect())
+            .unwrap_or_default()
+    }
+
+    /// `(document_frequency, frequency)` for `term`: how many documents
+    /// contain it, and how many times it occurs across the whole corpus.
+    pub fn term_stats(&self, term: &str) -> (usize, usize) {
+        match self.postings.get(term) {
+            Some(postings) => (postings.len(), postings.iter().map(|&(_, tf)| tf).sum()),
+            None => (0, 0),
+        }
+    }
+
+    /// Vocabulary terms starting with `prefix`, for prefix queries like `optim*`.
+    pub fn terms_with_prefix(&self, prefix: &str) -> Vec<String> {
+        self.postings
+            .keys()
+            .filter(|term| term.starts_with(prefix))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Wraps a BM25 score so it can live in a max-heap: `f64` has no `Ord`
+/// impl since `NaN` breaks total ordering, but scores are never `NaN`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ScoredDoc {
+    score: f64,
+    doc_id: usize,
+}
+
+impl Eq for ScoredDoc {}
+
+impl Ord for ScoredDoc {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score
+            .total_cmp(&other.score)
+            .then_with(|| self.doc_id.cmp(&other.doc_id))
+    }
+}
+
+impl PartialOrd for ScoredDoc {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toy_corpus() -> InvertedIndex {
+        InvertedIndex::build(&[
+            "the quick brown fox".to_string(),
+            "the quick fox jumps over the lazy fox".to_string(),
+            "rust performance and memory".to_string(),
+        ])
+    }
+
+    #[test]
+    fn term_stats_counts_document_frequency_and_total_occurrences() {
+        let index = toy_corpus();
+        assert_eq!(index.term_stats("fox"), (2, 3));
+        assert_eq!(index.term_stats("the"), (2, 3));
+        assert_eq!(index.term_stats("rust"), (1, 1));
+        assert_eq!(index.term_stats("absent"), (0, 0));
+    }
+
+    #[test]
+    fn doc_ids_returns_exact_matches_only() {
+        let index = toy_corpus();
+        let mut docs: Vec<_> = index.doc_ids("fox").into_iter().collect();
+        docs.sort_unstable();
+        assert_eq!(docs, vec![0, 1]);
+        assert!(index.doc_ids("nonexistent").is_empty());
+    }
+
+    #[test]
+    fn terms_with_prefix_matches_vocabulary() {
+        let index = toy_corpus();
+        let mut terms = index.terms_with_prefix("ju");
+        terms.sort();
+        assert_eq!(terms, vec!["jumps".to_string()]);
+        assert!(index.terms_with_prefix("zzz").is_empty());
+    }
+
+    #[test]
+    fn search_ranks_more_relevant_doc_first() {
+        let index = toy_corpus();
+        let results = index.search("fox", 10);
+        assert_eq!(results[0].0, 1, "doc 1 mentions fox twice, should outrank doc 0");
+        assert!(results[0].1 > results[1].1);
+    }
+
+    #[test]
+    fn search_respects_top_k() {
+        let index = toy_corpus();
+        assert_eq!(index.search("the quick fox", 1).len(), 1);
+        assert_eq!(index.search("the quick fox", 10).len(), 2);
+    }
+
+    #[test]
+    fn search_subset_only_scores_candidate_docs() {
+        let index = toy_corpus();
+        let candidates: HashSet<usize> = [0].into_iter().collect();
+        let results = index.search_subset(&["fox".to_string()], &candidates, 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, 0);
+    }
+
+    #[test]
+    fn empty_corpus_has_no_matches() {
+        let index = InvertedIndex::build(&[]);
+        assert!(index.search("anything", 5).is_empty());
+    }
+}
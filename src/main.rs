@@ -1,7 +1,18 @@
-use std::collections::{HashMap, BinaryHeap};
+use std::collections::{HashMap, HashSet, BinaryHeap};
 use std::cmp::Reverse;
 use std::time::Instant;
 
+mod cli;
+mod tokenize;
+mod tokenizer;
+mod stem;
+mod index;
+mod spell;
+mod query;
+
+use spell::SpellIndex;
+use tokenizer::Tokenizer;
+
 fn analyze_text_slow(text: &str) -> TextStats {
     let start = Instant::now();
 
@@ -55,61 +66,72 @@ fn analyze_text_slow(text: &str) -> TextStats {
         .map(|s| s.clone())
         .collect();
 
+    let spell_index = SpellIndex::build(&word_freq);
+
     TextStats {
         word_count: word_freq.len(),
         char_count,
         top_words,
         longest_words,
+        spell_index,
         time_ms: start.elapsed().as_millis(),
     }
 }
 
-fn analyze_text_fast(text: &str) -> TextStats {
+fn analyze_text_fast(text: &str, tokenizer: &Tokenizer, top_n: usize, longest_n: usize) -> TextStats {
     let start = Instant::now();
 
     let mut word_freq: HashMap<String, usize> = HashMap::with_capacity(10000);
     let mut char_count = 0;
     let mut longest_words_heap: BinaryHeap<Reverse<(usize, String)>> = BinaryHeap::new();
+    // Tracks which distinct words already occupy a heap slot, so a word
+    // repeated many times in the text only ever competes for one "longest
+    // words" slot instead of crowding out other distinct long words.
+    let mut longest_seen: HashSet<String> = HashSet::new();
 
     for word in text.split_ascii_whitespace() {
-        let mut clean_word = String::with_capacity(word.len());
-        for &ch in word.as_bytes() {
-            if ch.is_ascii_alphabetic() {
-                char_count += 1;
-                clean_word.push((ch | 0x20) as char);
-            }
-        }
+        let clean_word = tokenize::clean_word(word);
 
         if !clean_word.is_empty() {
+            char_count += clean_word.len();
             let len = clean_word.len();
-            
-            if longest_words_heap.len() < 5 {
-                longest_words_heap.push(Reverse((len, clean_word.clone())));
-            } else if let Some(&Reverse((min_len, _))) = longest_words_heap.peek() {
-                if len > min_len {
-                    longest_words_heap.pop();
+
+            if !longest_seen.contains(&clean_word) {
+                if longest_words_heap.len() < longest_n {
+                    longest_seen.insert(clean_word.clone());
                     longest_words_heap.push(Reverse((len, clean_word.clone())));
+                } else if let Some(&Reverse((min_len, _))) = longest_words_heap.peek() {
+                    if len > min_len {
+                        if let Some(Reverse((_, evicted))) = longest_words_heap.pop() {
+                            longest_seen.remove(&evicted);
+                        }
+                        longest_seen.insert(clean_word.clone());
+                        longest_words_heap.push(Reverse((len, clean_word.clone())));
+                    }
                 }
             }
-            
-            *word_freq.entry(clean_word).or_insert(0) += 1;
+
+            for token in tokenizer.tokenize(&clean_word) {
+                *word_freq.entry(token).or_insert(0) += 1;
+            }
         }
     }
 
     let word_count = word_freq.len();
-    
+    let spell_index = SpellIndex::build(&word_freq);
+
     let mut top_words_heap: BinaryHeap<(usize, String)> = word_freq
         .into_iter()
         .map(|(w, c)| (c, w))
         .collect();
-    
-    let mut top_words = Vec::with_capacity(10);
-    for _ in 0..10 {
+
+    let mut top_words = Vec::with_capacity(top_n);
+    for _ in 0..top_n {
         if let Some((count, word)) = top_words_heap.pop() {
             top_words.push((word, count));
         }
     }
-    
+
     let mut longest_vec: Vec<_> = longest_words_heap.into_iter().map(|Reverse(x)| x).collect();
     longest_vec.sort_unstable_by(|a, b| b.0.cmp(&a.0));
     let longest_words: Vec<String> = longest_vec.into_iter().map(|(_, w)| w).collect();
@@ -119,6 +141,7 @@ fn analyze_text_fast(text: &str) -> TextStats {
         char_count,
         top_words,
         longest_words,
+        spell_index,
         time_ms: start.elapsed().as_millis(),
     }
 }
@@ -129,9 +152,18 @@ struct TextStats {
     char_count: usize,
     top_words: Vec<(String, usize)>,
     longest_words: Vec<String>,
+    spell_index: SpellIndex,
     time_ms: u128,
 }
 
+impl TextStats {
+    /// "Did you mean?" suggestions: in-vocabulary words within edit
+    /// distance `d` of `word`, most frequent first.
+    fn suggest(&self, word: &str, d: usize) -> Vec<(String, usize)> {
+        self.spell_index.query(word, d)
+    }
+}
+
 fn generate_test_text(size: usize) -> String {
     let base_words = vec![
         "rust", "performance", "optimization", "memory", "speed", "efficiency",
@@ -160,26 +192,10 @@ fn generate_test_text(size: usize) -> String {
 }
 
 fn main() {
-    let text = generate_test_text(50_000);
-
-    println!("Analyzing {} bytes of text...\n", text.len());
-
-    let stats_slow = analyze_text_slow(&text);
-    println!("SLOW VERSION:");
-    println!("  Unique words: {}", stats_slow.word_count);
-    println!("  Total chars: {}", stats_slow.char_count);
-    println!("  Top 10 words: {:?}", stats_slow.top_words);
-    println!("  Longest words: {:?}", stats_slow.longest_words);
-    println!("  Time: {} ms\n", stats_slow.time_ms);
-
-    let stats_fast = analyze_text_fast(&text);
-    println!("FAST VERSION:");
-    println!("  Unique words: {}", stats_fast.word_count);
-    println!("  Total chars: {}", stats_fast.char_count);
-    println!("  Top 10 words: {:?}", stats_fast.top_words);
-    println!("  Longest words: {:?}", stats_fast.longest_words);
-    println!("  Time: {} ms", stats_fast.time_ms);
-    
-    let speedup = stats_slow.time_ms as f64 / stats_fast.time_ms.max(1) as f64;
-    println!("\nSpeedup: {:.1}x faster!", speedup);
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if let Err(err) = cli::run(args) {
+        eprintln!("error: {err}");
+        std::process::exit(1);
+    }
 }
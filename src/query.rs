@@ -0,0 +1,307 @@
+//! Boolean query tree (AND/OR/prefix) evaluated against an [`InvertedIndex`],
+//! with BM25 layered on top for ordering the matches.
+//!
+//! Grammar (OR binds loosest, parens override):
+//!   or_expr   := and_expr ('OR' and_expr)*
+//!   and_expr  := atom ('AND' atom)*
+//!   atom      := '(' or_expr ')' | WORD['*']
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::index::InvertedIndex;
+
+pub enum Operation {
+    And(Vec<Operation>),
+    Or(Vec<Operation>),
+    Query { term: String, prefix: bool },
+}
+
+impl Operation {
+    /// Doc-ids matching this subtree: `Query` resolves via the index
+    /// (prefix queries union every vocabulary term sharing the prefix),
+    /// `And` intersects its children, `Or` unions them.
+    pub fn eval(&self, index: &InvertedIndex) -> HashSet<usize> {
+        match self {
+            Operation::Query { term, prefix } => {
+                if *prefix {
+                    index
+                        .terms_with_prefix(term)
+                        .iter()
+                        .flat_map(|t| index.doc_ids(t))
+                        .collect()
+                } else {
+                    index.doc_ids(term)
+                }
+            }
+            Operation::And(children) => {
+                let mut sets = children.iter().map(|op| op.eval(index));
+                match sets.next() {
+                    Some(first) => sets.fold(first, |acc, s| acc.intersection(&s).copied().collect()),
+                    None => HashSet::new(),
+                }
+            }
+            Operation::Or(children) => {
+                children.iter().fold(HashSet::new(), |mut acc, op| {
+                    acc.extend(op.eval(index));
+                    acc
+                })
+            }
+        }
+    }
+
+    /// Every leaf term this subtree references, expanding prefix queries
+    /// against `index` — used to BM25-score the matching doc-ids.
+    pub fn terms(&self, index: &InvertedIndex) -> Vec<String> {
+        match self {
+            Operation::Query { term, prefix } => {
+                if *prefix {
+                    index.terms_with_prefix(term)
+                } else {
+                    vec![term.clone()]
+                }
+            }
+            Operation::And(children) | Operation::Or(children) => {
+                children.iter().flat_map(|op| op.terms(index)).collect()
+            }
+        }
+    }
+
+    /// Evaluates this query against `index` and returns the `top_k`
+    /// matches ordered by BM25 score.
+    pub fn search(&self, index: &InvertedIndex, top_k: usize) -> Vec<(usize, f64)> {
+        let matches = self.eval(index);
+        let terms = self.terms(index);
+        index.search_subset(&terms, &matches, top_k)
+    }
+}
+
+impl fmt::Debug for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_indented(op: &Operation, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+            let pad = "  ".repeat(depth);
+            match op {
+                Operation::Query { term, prefix } => {
+                    writeln!(f, "{pad}Query({term}{})", if *prefix { "*" } else { "" })
+                }
+                Operation::And(children) => {
+                    writeln!(f, "{pad}And")?;
+                    children.iter().try_for_each(|child| write_indented(child, f, depth + 1))
+                }
+                Operation::Or(children) => {
+                    writeln!(f, "{pad}Or")?;
+                    children.iter().try_for_each(|child| write_indented(child, f, depth + 1))
+                }
+            }
+        }
+        write_indented(self, f, 0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    And,
+    Or,
+    Word(String),
+}
+
+fn lex(query: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '(' => {
+                tokens.push(Token::LParen);
+                chars.next();
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                chars.next();
+            }
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c == '(' || c == ')' || c.is_whitespace() {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(match word.to_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    _ => Token::Word(word),
+                });
+            }
+        }
+    }
+
+    tokens
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<Operation, String> {
+        let mut children = vec![self.parse_and()?];
+        while matches!(self.peek(), Some(Token::Or)) {
+            self.pos += 1;
+            children.push(self.parse_and()?);
+        }
+        Ok(if children.len() == 1 { children.pop().unwrap() } else { Operation::Or(children) })
+    }
+
+    fn parse_and(&mut self) -> Result<Operation, String> {
+        let mut children = vec![self.parse_atom()?];
+        while matches!(self.peek(), Some(Token::And)) {
+            self.pos += 1;
+            children.push(self.parse_atom()?);
+        }
+        Ok(if children.len() == 1 { children.pop().unwrap() } else { Operation::And(children) })
+    }
+
+    fn parse_atom(&mut self) -> Result<Operation, String> {
+        match self.advance().cloned() {
+            Some(Token::LParen) => {
+                let inner = self.parse_or()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(inner),
+                    other => Err(format!("expected ')', found {other:?}")),
+                }
+            }
+            Some(Token::Word(word)) => match word.strip_suffix('*') {
+                Some(stem) => Ok(Operation::Query { term: crate::tokenize::clean_word(stem), prefix: true }),
+                None => Ok(Operation::Query { term: crate::tokenize::clean_word(&word), prefix: false }),
+            },
+            other => Err(format!("expected a term or '(', found {other:?}")),
+        }
+    }
+}
+
+/// Parses a query string like `rust AND (speed OR efficiency)` or
+/// `optim*` into an [`Operation`] tree.
+pub fn parse(query: &str) -> Result<Operation, String> {
+    let tokens = lex(query);
+    let mut parser = Parser { tokens: &tokens, pos: 0 };
+    let op = parser.parse_or()?;
+    if parser.pos != tokens.len() {
+        return Err(format!("unexpected trailing input at token {}", parser.pos));
+    }
+    Ok(op)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::index::InvertedIndex;
+
+    fn toy_corpus() -> InvertedIndex {
+        InvertedIndex::build(&[
+            "rust is great for performance".to_string(),
+            "python is great for scripting".to_string(),
+            "rust has zero cost abstractions".to_string(),
+        ])
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        // `rust AND great OR scripting` should parse as `(rust AND great) OR scripting`.
+        let op = parse("rust AND great OR scripting").unwrap();
+        match op {
+            Operation::Or(children) => {
+                assert_eq!(children.len(), 2);
+                assert!(matches!(&children[0], Operation::And(inner) if inner.len() == 2));
+                assert!(matches!(&children[1], Operation::Query { term, .. } if term == "scripting"));
+            }
+            other => panic!("expected Or at the top level, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parens_override_default_precedence() {
+        // `rust AND (great OR scripting)` should stay a single And with an Or child.
+        let op = parse("rust AND (great OR scripting)").unwrap();
+        match op {
+            Operation::And(children) => {
+                assert_eq!(children.len(), 2);
+                assert!(matches!(&children[1], Operation::Or(inner) if inner.len() == 2));
+            }
+            other => panic!("expected And at the top level, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn prefix_suffix_marks_a_prefix_query() {
+        let op = parse("perf*").unwrap();
+        assert!(matches!(op, Operation::Query { ref term, prefix: true } if term == "perf"));
+    }
+
+    #[test]
+    fn terms_are_cleaned_like_the_index_normalizes_them() {
+        let op = parse("Rust! AND GREAT").unwrap();
+        match op {
+            Operation::And(children) => {
+                assert!(matches!(&children[0], Operation::Query { term, .. } if term == "rust"));
+                assert!(matches!(&children[1], Operation::Query { term, .. } if term == "great"));
+            }
+            other => panic!("expected And at the top level, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn eval_intersects_and_unions_or() {
+        let index = toy_corpus();
+        let op = parse("rust AND performance").unwrap();
+        let mut docs: Vec<_> = op.eval(&index).into_iter().collect();
+        docs.sort_unstable();
+        assert_eq!(docs, vec![0]);
+
+        let op = parse("scripting OR abstractions").unwrap();
+        let mut docs: Vec<_> = op.eval(&index).into_iter().collect();
+        docs.sort_unstable();
+        assert_eq!(docs, vec![1, 2]);
+    }
+
+    #[test]
+    fn eval_expands_prefix_queries_across_the_vocabulary() {
+        let index = toy_corpus();
+        let op = parse("perf*").unwrap();
+        let mut docs: Vec<_> = op.eval(&index).into_iter().collect();
+        docs.sort_unstable();
+        assert_eq!(docs, vec![0]);
+    }
+
+    #[test]
+    fn search_returns_docs_ranked_by_bm25() {
+        let index = toy_corpus();
+        let op = parse("rust! AND great").unwrap();
+        let results = op.search(&index, 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, 0);
+    }
+
+    #[test]
+    fn parse_rejects_unbalanced_parens_and_trailing_input() {
+        assert!(parse("(rust AND great").is_err());
+        assert!(parse("rust)").is_err());
+    }
+}
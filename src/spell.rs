@@ -0,0 +1,181 @@
+//! BK-tree spelling suggestions ("did you mean?") over a vocabulary of
+//! known words, keyed by Levenshtein edit distance.
+
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct BkNode {
+    word: String,
+    freq: usize,
+    children: HashMap<usize, Box<BkNode>>,
+}
+
+impl BkNode {
+    fn new(word: String, freq: usize) -> Self {
+        BkNode { word, freq, children: HashMap::new() }
+    }
+
+    fn insert(&mut self, word: String, freq: usize) {
+        let dist = levenshtein(&self.word, &word);
+        if dist == 0 {
+            self.freq = self.freq.max(freq);
+            return;
+        }
+        match self.children.get_mut(&dist) {
+            Some(child) => child.insert(word, freq),
+            None => {
+                self.children.insert(dist, Box::new(BkNode::new(word, freq)));
+            }
+        }
+    }
+
+    fn query(&self, target: &str, tolerance: usize, out: &mut Vec<(String, usize)>) {
+        let dist = levenshtein(&self.word, target);
+        if dist <= tolerance {
+            out.push((self.word.clone(), self.freq));
+        }
+
+        let lo = dist.saturating_sub(tolerance);
+        let hi = dist + tolerance;
+        for (&child_dist, child) in &self.children {
+            if child_dist >= lo && child_dist <= hi {
+                child.query(target, tolerance, out);
+            }
+        }
+    }
+}
+
+/// BK-tree over a vocabulary, built from `(word, frequency)` pairs so
+/// suggestions can be ranked by how common the word is.
+#[derive(Debug, Default)]
+pub struct SpellIndex {
+    root: Option<BkNode>,
+}
+
+impl SpellIndex {
+    pub fn build(word_freq: &HashMap<String, usize>) -> Self {
+        let mut index = SpellIndex::default();
+        for (word, &freq) in word_freq {
+            index.insert(word.clone(), freq);
+        }
+        index
+    }
+
+    fn insert(&mut self, word: String, freq: usize) {
+        match &mut self.root {
+            Some(root) => root.insert(word, freq),
+            None => self.root = Some(BkNode::new(word, freq)),
+        }
+    }
+
+    /// Returns in-vocabulary words within edit distance `d` of `target`,
+    /// most frequent first (ties broken alphabetically by word).
+    pub fn query(&self, target: &str, d: usize) -> Vec<(String, usize)> {
+        let Some(root) = &self.root else {
+            return Vec::new();
+        };
+
+        let mut matches = Vec::new();
+        root.query(target, d, &mut matches);
+        matches.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        matches
+    }
+}
+
+/// Classic O(len(a) * len(b)) Levenshtein edit distance.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut prev_diag = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let tmp = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                prev_diag
+            } else {
+                1 + prev_diag.min(row[j]).min(row[j - 1])
+            };
+            prev_diag = tmp;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vocab(words: &[(&str, usize)]) -> HashMap<String, usize> {
+        words.iter().map(|&(w, f)| (w.to_string(), f)).collect()
+    }
+
+    #[test]
+    fn levenshtein_matches_known_distances() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("same", "same"), 0);
+        assert_eq!(levenshtein("", "abc"), 3);
+    }
+
+    #[test]
+    fn query_finds_exact_match_at_distance_zero() {
+        let index = SpellIndex::build(&vocab(&[("rust", 5), ("rush", 2)]));
+        let matches = index.query("rust", 0);
+        assert_eq!(matches, vec![("rust".to_string(), 5)]);
+    }
+
+    #[test]
+    fn query_respects_edit_distance_tolerance() {
+        let index = SpellIndex::build(&vocab(&[("rust", 5), ("rush", 2), ("crust", 1)]));
+        // "rust" -> "rush" is distance 1, "rust" -> "crust" is distance 1.
+        let mut within_one: Vec<_> = index.query("rust", 1).into_iter().map(|(w, _)| w).collect();
+        within_one.sort();
+        assert_eq!(within_one, vec!["crust".to_string(), "rush".to_string(), "rust".to_string()]);
+
+        assert!(index.query("xyz", 1).is_empty());
+    }
+
+    #[test]
+    fn query_ranks_by_frequency_then_word() {
+        let index = SpellIndex::build(&vocab(&[("cat", 1), ("cot", 10), ("cut", 5)]));
+        let matches = index.query("cat", 1);
+        assert_eq!(matches, vec![("cot".to_string(), 10), ("cut".to_string(), 5), ("cat".to_string(), 1)]);
+    }
+
+    #[test]
+    fn query_prunes_children_outside_the_triangle_inequality_window_without_missing_matches() {
+        // A vocabulary spread across several distinct BK-tree distance buckets
+        // from the root so a query must descend into more than the root's
+        // own bucket to find every match within tolerance.
+        let index = SpellIndex::build(&vocab(&[
+            ("bat", 1),
+            ("cat", 1),
+            ("cot", 1),
+            ("dog", 1),
+            ("dogs", 1),
+            ("frog", 1),
+        ]));
+        let mut matches: Vec<_> = index.query("cat", 1).into_iter().map(|(w, _)| w).collect();
+        matches.sort();
+        assert_eq!(matches, vec!["bat".to_string(), "cat".to_string(), "cot".to_string()]);
+    }
+
+    #[test]
+    fn empty_vocabulary_has_no_matches() {
+        let index = SpellIndex::build(&HashMap::new());
+        assert!(index.query("anything", 3).is_empty());
+    }
+
+    #[test]
+    fn insert_keeps_the_larger_frequency_for_duplicate_words() {
+        let mut freq = HashMap::new();
+        freq.insert("rust".to_string(), 3);
+        let mut index = SpellIndex::build(&freq);
+        index.insert("rust".to_string(), 7);
+        index.insert("rust".to_string(), 1);
+        assert_eq!(index.query("rust", 0), vec![("rust".to_string(), 7)]);
+    }
+}
@@ -0,0 +1,307 @@
+//! Porter stemmer (Porter, 1980): a fixed sequence of suffix-rewrite steps
+//! driven by a word's "measure" `m`, the number of vowel-consonant groups
+//! in its stem. Operates on lowercase ASCII words only.
+
+fn is_vowel(chars: &[char], i: usize) -> bool {
+    match chars[i] {
+        'a' | 'e' | 'i' | 'o' | 'u' => true,
+        'y' => i != 0 && !is_vowel(chars, i - 1),
+        _ => false,
+    }
+}
+
+/// Measure `m`: the number of vowel-sequence -> consonant-sequence
+/// transitions in `chars`, i.e. how many `VC` groups the word has.
+fn measure(chars: &[char]) -> usize {
+    let mut m = 0;
+    let mut in_vowel_run = false;
+    for i in 0..chars.len() {
+        if is_vowel(chars, i) {
+            in_vowel_run = true;
+        } else {
+            if in_vowel_run {
+                m += 1;
+            }
+            in_vowel_run = false;
+        }
+    }
+    m
+}
+
+fn contains_vowel(chars: &[char]) -> bool {
+    (0..chars.len()).any(|i| is_vowel(chars, i))
+}
+
+/// True if `chars` ends `consonant-vowel-consonant` and that final
+/// consonant is not `w`, `x`, or `y` (the `*o` condition in Porter's paper).
+fn ends_cvc(chars: &[char]) -> bool {
+    let n = chars.len();
+    if n < 3 {
+        return false;
+    }
+    !is_vowel(chars, n - 1)
+        && is_vowel(chars, n - 2)
+        && !is_vowel(chars, n - 3)
+        && !matches!(chars[n - 1], 'w' | 'x' | 'y')
+}
+
+fn ends_double_consonant(chars: &[char]) -> bool {
+    let n = chars.len();
+    n >= 2 && chars[n - 1] == chars[n - 2] && !is_vowel(chars, n - 1)
+}
+
+fn strip_suffix(chars: &[char], suffix: &str) -> Option<Vec<char>> {
+    let suffix: Vec<char> = suffix.chars().collect();
+    if chars.len() >= suffix.len() && chars[chars.len() - suffix.len()..] == suffix[..] {
+        Some(chars[..chars.len() - suffix.len()].to_vec())
+    } else {
+        None
+    }
+}
+
+fn measure_of(chars: &[char], suffix: &str) -> Option<usize> {
+    strip_suffix(chars, suffix).map(|stem| measure(&stem))
+}
+
+fn step1a(chars: Vec<char>) -> Vec<char> {
+    if let Some(stem) = strip_suffix(&chars, "sses") {
+        let mut s = stem;
+        s.extend("ss".chars());
+        return s;
+    }
+    if let Some(stem) = strip_suffix(&chars, "ies") {
+        let mut s = stem;
+        s.push('i');
+        return s;
+    }
+    if strip_suffix(&chars, "ss").is_some() {
+        return chars;
+    }
+    if let Some(stem) = strip_suffix(&chars, "s") {
+        return stem;
+    }
+    chars
+}
+
+fn step1b(chars: Vec<char>) -> Vec<char> {
+    // `eed` is the longest of the three suffixes this step matches, so if
+    // it's present it wins outright: either `m>0` rewrites it to `ee`, or
+    // the word is left alone — we must not also try `ed`/`ing` (e.g. "feed"
+    // ends in both "eed" and "ed"; only the "eed" rule may fire).
+    if let Some(stem) = strip_suffix(&chars, "eed") {
+        return if measure(&stem) > 0 {
+            let mut s = stem;
+            s.extend("ee".chars());
+            s
+        } else {
+            chars
+        };
+    }
+
+    let after_ed = strip_suffix(&chars, "ed").filter(|s| contains_vowel(s));
+    let after_ing = strip_suffix(&chars, "ing").filter(|s| contains_vowel(s));
+
+    let Some(mut stem) = after_ed.or(after_ing) else {
+        return chars;
+    };
+
+    if strip_suffix(&stem, "at").is_some()
+        || strip_suffix(&stem, "bl").is_some()
+        || strip_suffix(&stem, "iz").is_some()
+    {
+        stem.push('e');
+    } else if ends_double_consonant(&stem) && !matches!(stem.last(), Some('l' | 's' | 'z')) {
+        stem.pop();
+    } else if measure(&stem) == 1 && ends_cvc(&stem) {
+        stem.push('e');
+    }
+    stem
+}
+
+fn step1c(chars: Vec<char>) -> Vec<char> {
+    if let Some(mut stem) = strip_suffix(&chars, "y") {
+        if contains_vowel(&stem) {
+            stem.push('i');
+            return stem;
+        }
+    }
+    chars
+}
+
+fn replace_if_measure(chars: Vec<char>, suffix: &str, min_m: usize, replacement: &str) -> Vec<char> {
+    match measure_of(&chars, suffix) {
+        Some(m) if m > min_m => {
+            let mut s = strip_suffix(&chars, suffix).unwrap();
+            s.extend(replacement.chars());
+            s
+        }
+        _ => chars,
+    }
+}
+
+fn step2(chars: Vec<char>) -> Vec<char> {
+    const MAP: &[(&str, &str)] = &[
+        ("ational", "ate"),
+        ("tional", "tion"),
+        ("enci", "ence"),
+        ("anci", "ance"),
+        ("izer", "ize"),
+        ("abli", "able"),
+        ("alli", "al"),
+        ("entli", "ent"),
+        ("eli", "e"),
+        ("ousli", "ous"),
+        ("ization", "ize"),
+        ("ation", "ate"),
+        ("ator", "ate"),
+        ("alism", "al"),
+        ("iveness", "ive"),
+        ("fulness", "ful"),
+        ("ousness", "ous"),
+        ("aliti", "al"),
+        ("iviti", "ive"),
+        ("biliti", "ble"),
+    ];
+    for (suffix, replacement) in MAP {
+        if strip_suffix(&chars, suffix).is_some() {
+            return replace_if_measure(chars, suffix, 0, replacement);
+        }
+    }
+    chars
+}
+
+fn step3(chars: Vec<char>) -> Vec<char> {
+    const MAP: &[(&str, &str)] = &[
+        ("icate", "ic"),
+        ("ative", ""),
+        ("alize", "al"),
+        ("iciti", "ic"),
+        ("ical", "ic"),
+        ("ful", ""),
+        ("ness", ""),
+    ];
+    for (suffix, replacement) in MAP {
+        if strip_suffix(&chars, suffix).is_some() {
+            return replace_if_measure(chars, suffix, 0, replacement);
+        }
+    }
+    chars
+}
+
+fn step4(chars: Vec<char>) -> Vec<char> {
+    const SUFFIXES: &[&str] = &[
+        "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent", "ou",
+        "ism", "ate", "iti", "ous", "ive", "ize",
+    ];
+    for suffix in SUFFIXES {
+        if let Some(stem) = strip_suffix(&chars, suffix) {
+            if measure(&stem) > 1 {
+                return stem;
+            }
+            return chars;
+        }
+    }
+    if let Some(stem) = strip_suffix(&chars, "ion") {
+        if measure(&stem) > 1 && matches!(stem.last(), Some('s' | 't')) {
+            return stem;
+        }
+    }
+    chars
+}
+
+fn step5a(chars: Vec<char>) -> Vec<char> {
+    let Some(stem) = strip_suffix(&chars, "e") else {
+        return chars;
+    };
+    let m = measure(&stem);
+    if m > 1 || (m == 1 && !ends_cvc(&stem)) {
+        stem
+    } else {
+        chars
+    }
+}
+
+fn step5b(chars: Vec<char>) -> Vec<char> {
+    if measure(&chars) > 1 && ends_double_consonant(&chars) && chars.last() == Some(&'l') {
+        let mut s = chars;
+        s.pop();
+        s
+    } else {
+        chars
+    }
+}
+
+/// Reduces `word` to its Porter stem. `word` is expected to already be
+/// lowercase ASCII (as produced by [`crate::tokenize::clean_word`]).
+pub fn porter_stem(word: &str) -> String {
+    if word.len() <= 2 {
+        return word.to_string();
+    }
+
+    let chars: Vec<char> = word.chars().collect();
+    let chars = step1a(chars);
+    let chars = step1b(chars);
+    let chars = step1c(chars);
+    let chars = step2(chars);
+    let chars = step3(chars);
+    let chars = step4(chars);
+    let chars = step5a(chars);
+    let chars = step5b(chars);
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leading_y_is_a_consonant() {
+        let word: Vec<char> = "youth".chars().collect();
+        assert!(!is_vowel(&word, 0));
+    }
+
+    #[test]
+    fn y_after_a_consonant_is_a_vowel() {
+        let word: Vec<char> = "sky".chars().collect();
+        assert!(is_vowel(&word, 2));
+    }
+
+    #[test]
+    fn stems_known_porter_pairs() {
+        let pairs = [
+            ("caresses", "caress"),
+            ("ponies", "poni"),
+            ("cats", "cat"),
+            ("feed", "feed"),
+            ("agreed", "agre"),
+            ("plastered", "plaster"),
+            ("motoring", "motor"),
+            ("sing", "sing"),
+            ("hopping", "hop"),
+            ("tanned", "tan"),
+            ("falling", "fall"),
+            ("happy", "happi"),
+            ("sky", "sky"),
+            ("relational", "relat"),
+            ("conditional", "condit"),
+            ("hopeful", "hope"),
+            ("goodness", "good"),
+        ];
+        for (word, expected) in pairs {
+            assert_eq!(porter_stem(word), expected, "stemming {word}");
+        }
+    }
+
+    #[test]
+    fn does_not_overcount_measure_for_leading_y_words() {
+        // Regression: a buggy leading-y-as-vowel classification gave
+        // "yllness" a spurious m=1, incorrectly stripping "ness".
+        assert_eq!(porter_stem("yllness"), "yllness");
+    }
+
+    #[test]
+    fn short_words_are_left_alone() {
+        assert_eq!(porter_stem("is"), "is");
+        assert_eq!(porter_stem("a"), "a");
+    }
+}
@@ -0,0 +1,23 @@
+//! Shared ASCII lowercase/alphabetic cleaning used by both the word-count
+//! demo and the inverted index, so the two always agree on what a "word" is.
+
+/// Lowercases `word` and strips every non-ASCII-alphabetic byte, mirroring
+/// the cleaning loop `analyze_text_fast` has always used.
+pub fn clean_word(word: &str) -> String {
+    let mut clean = String::with_capacity(word.len());
+    for &ch in word.as_bytes() {
+        if ch.is_ascii_alphabetic() {
+            clean.push((ch | 0x20) as char);
+        }
+    }
+    clean
+}
+
+/// Splits `text` on ASCII whitespace and cleans each token, dropping any
+/// token that turns out empty (pure punctuation, digits, etc.).
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split_ascii_whitespace()
+        .map(clean_word)
+        .filter(|w| !w.is_empty())
+        .collect()
+}
@@ -0,0 +1,70 @@
+//! Configurable tokenization stage: optional stopword removal and Porter
+//! stemming on top of the base ASCII cleaning, so callers can merge
+//! inflections ("optimize"/"optimizing"/"optimization") before counting.
+
+use std::collections::HashSet;
+
+use crate::stem::porter_stem;
+use crate::tokenize::tokenize;
+
+#[derive(Debug, Clone)]
+pub struct Tokenizer {
+    stem: bool,
+    stopwords: HashSet<String>,
+}
+
+impl Tokenizer {
+    pub fn builder() -> TokenizerBuilder {
+        TokenizerBuilder::default()
+    }
+
+    /// Tokenizes `text`, dropping stopwords and stemming if configured.
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        tokenize(text)
+            .into_iter()
+            .filter(|word| !self.stopwords.contains(word))
+            .map(|word| if self.stem { porter_stem(&word) } else { word })
+            .collect()
+    }
+}
+
+impl Default for Tokenizer {
+    /// The raw-token path: no stemming, no stopwords.
+    fn default() -> Self {
+        TokenizerBuilder::default().build()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TokenizerBuilder {
+    stem: bool,
+    stopwords: HashSet<String>,
+}
+
+impl TokenizerBuilder {
+    pub fn stem(mut self, stem: bool) -> Self {
+        self.stem = stem;
+        self
+    }
+
+    pub fn stopwords<I: IntoIterator<Item = String>>(mut self, stopwords: I) -> Self {
+        self.stopwords = stopwords.into_iter().collect();
+        self
+    }
+
+    pub fn build(self) -> Tokenizer {
+        Tokenizer { stem: self.stem, stopwords: self.stopwords }
+    }
+}
+
+/// A small set of common English stopwords, enough to keep `top_words`
+/// from being dominated by function words.
+pub fn default_stopwords() -> HashSet<String> {
+    [
+        "the", "a", "an", "and", "or", "but", "of", "to", "in", "on", "for", "is", "are", "was",
+        "were", "be", "been", "being", "with", "as", "at", "by", "it", "this", "that", "from",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}